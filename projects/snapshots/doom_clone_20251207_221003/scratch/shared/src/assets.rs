@@ -0,0 +1,147 @@
+// scratch/shared/src/assets.rs
+// Asset persistence + CRUD API using Actix-web + SQLx (PostgreSQL)
+// Endpoints (all wrapped by AuthGuard; mutations require the editor role):
+//  - GET    /assets
+//  - POST   /assets
+//  - GET    /assets/{id}
+//  - PUT    /assets/{id}
+//  - DELETE /assets/{id}
+//
+// Mirrors the users.rs module: a FromRow row type, a repository of async
+// functions over a &PgPool, and an Actix config(cfg) entry point.
+
+use actix_web::{web, HttpResponse};
+use sqlx::PgPool;
+use serde::{Serialize, Deserialize};
+use validator::Validate;
+
+use crate::users::{AppState, AuthGuard};
+use crate::rbac::RequirePermission;
+use crate::error::Error;
+use crate::validation;
+use crate::id_codec;
+
+// The assets table is created by migration `migrations/0006_assets.sql`.
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Asset {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssetResponse {
+    // Opaque public id; the raw SERIAL never leaves the service.
+    pub id: String,
+    pub name: String,
+}
+
+impl From<Asset> for AssetResponse {
+    fn from(a: Asset) -> Self {
+        AssetResponse {
+            id: id_codec::encode(a.id as u64),
+            name: a.name,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewAsset {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+}
+
+// --------------------
+// Repository
+// --------------------
+pub async fn create(pool: &PgPool, new: &NewAsset) -> Result<Asset, sqlx::Error> {
+    sqlx::query_as::<_, Asset>(
+        "INSERT INTO assets (name) VALUES ($1) RETURNING id, name",
+    )
+    .bind(&new.name)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn list(pool: &PgPool) -> Result<Vec<Asset>, sqlx::Error> {
+    sqlx::query_as::<_, Asset>("SELECT id, name FROM assets ORDER BY id")
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get(pool: &PgPool, id: i32) -> Result<Asset, sqlx::Error> {
+    sqlx::query_as::<_, Asset>("SELECT id, name FROM assets WHERE id = $1")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn update(pool: &PgPool, id: i32, new: &NewAsset) -> Result<Asset, sqlx::Error> {
+    sqlx::query_as::<_, Asset>(
+        "UPDATE assets SET name = $2 WHERE id = $1 RETURNING id, name",
+    )
+    .bind(id)
+    .bind(&new.name)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete(pool: &PgPool, id: i32) -> Result<u64, sqlx::Error> {
+    let res = sqlx::query("DELETE FROM assets WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(res.rows_affected())
+}
+
+// --------------------
+// Route configuration
+// --------------------
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/assets")
+            // AuthGuard is outermost so it injects Claims before RequirePermission reads them.
+            .wrap(RequirePermission("asset"))
+            .wrap(AuthGuard)
+            .route("", web::get(list_assets).post(create_asset))
+            .route("/{id}", web::get(get_asset).put(update_asset).delete(delete_asset)),
+    );
+}
+
+// --------------------
+// Handlers
+// --------------------
+async fn list_assets(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let rows = list(&state.db_pool).await?;
+    let resp: Vec<AssetResponse> = rows.into_iter().map(AssetResponse::from).collect();
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+async fn get_asset(state: web::Data<AppState>, path: web::Path<String>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let row = get(&state.db_pool, id).await?;
+    Ok(HttpResponse::Ok().json(AssetResponse::from(row)))
+}
+
+async fn create_asset(state: web::Data<AppState>, payload: web::Json<NewAsset>) -> Result<HttpResponse, Error> {
+    payload.validate().map_err(|e| Error::Validation(validation::field_errors(&e)))?;
+    let row = create(&state.db_pool, &payload.into_inner()).await?;
+    Ok(HttpResponse::Created().json(AssetResponse::from(row)))
+}
+
+async fn update_asset(state: web::Data<AppState>, path: web::Path<String>, payload: web::Json<NewAsset>) -> Result<HttpResponse, Error> {
+    payload.validate().map_err(|e| Error::Validation(validation::field_errors(&e)))?;
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let row = update(&state.db_pool, id, &payload.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(AssetResponse::from(row)))
+}
+
+async fn delete_asset(state: web::Data<AppState>, path: web::Path<String>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let affected = delete(&state.db_pool, id).await?;
+    if affected == 0 {
+        Err(Error::NotFound)
+    } else {
+        Ok(HttpResponse::NoContent().finish())
+    }
+}
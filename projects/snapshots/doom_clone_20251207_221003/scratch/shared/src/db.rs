@@ -0,0 +1,104 @@
+// scratch/shared/src/db.rs
+// Connection-pool construction and the startup migration runner.
+//
+// The pool is built from `DATABASE_URL`; `migrate()` applies the embedded SQL
+// migrations in order, recording each applied version in a `_migrations` table
+// so repeated startups are idempotent. `bootstrap()` ties the two together and
+// is the entry point a server should call before it starts serving.
+
+use sqlx::PgPool;
+use sqlx::postgres::PgPoolOptions;
+use std::time::Duration;
+
+use crate::users::AppState;
+
+// Embedded migrations, applied in listed order. Each entry is a monotonically
+// increasing version plus the raw SQL pulled in at compile time.
+const MIGRATIONS: &[(i64, &str, &str)] = &[
+    (1, "users", include_str!("../migrations/0001_users.sql")),
+    (2, "levels", include_str!("../migrations/0002_levels.sql")),
+    (3, "weapons", include_str!("../migrations/0003_weapons.sql")),
+    (4, "monsters", include_str!("../migrations/0004_monsters.sql")),
+    (5, "rbac", include_str!("../migrations/0005_rbac.sql")),
+    (6, "assets", include_str!("../migrations/0006_assets.sql")),
+];
+
+fn max_connections() -> u32 {
+    std::env::var("DB_MAX_CONNECTIONS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(5)
+}
+
+fn acquire_timeout() -> Duration {
+    let secs = std::env::var("DB_ACQUIRE_TIMEOUT_SECONDS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(30);
+    Duration::from_secs(secs)
+}
+
+/// Build the connection pool from `DATABASE_URL`.
+pub async fn connect() -> Result<PgPool, sqlx::Error> {
+    let url = std::env::var("DATABASE_URL")
+        .map_err(|_| sqlx::Error::Configuration("DATABASE_URL is not set".into()))?;
+    PgPoolOptions::new()
+        .max_connections(max_connections())
+        .acquire_timeout(acquire_timeout())
+        .connect(&url)
+        .await
+}
+
+/// Cheap liveness probe for readiness checks.
+pub async fn health_check(pool: &PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT 1").execute(pool).await?;
+    Ok(())
+}
+
+/// Apply any migrations that have not yet been recorded in `_migrations`.
+pub async fn migrate(pool: &PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS _migrations (\
+             version BIGINT PRIMARY KEY, \
+             name TEXT NOT NULL, \
+             applied_at TIMESTAMPTZ NOT NULL DEFAULT NOW())",
+    )
+    .execute(pool)
+    .await?;
+
+    for (version, name, sql) in MIGRATIONS {
+        let already: Option<i64> =
+            sqlx::query_scalar("SELECT version FROM _migrations WHERE version = $1")
+                .bind(version)
+                .fetch_optional(pool)
+                .await?;
+        if already.is_some() {
+            continue;
+        }
+
+        // Run the migration body and record it atomically.
+        let mut tx = pool.begin().await?;
+        sqlx::raw_sql(sql).execute(&mut *tx).await?;
+        sqlx::query("INSERT INTO _migrations (version, name) VALUES ($1, $2)")
+            .bind(version)
+            .bind(name)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+    }
+
+    Ok(())
+}
+
+/// Connect, run migrations, and hand back the shared application state.
+///
+/// Fails closed if `JWT_SECRET` is unset so the service never starts with a
+/// forgeable signing key.
+pub async fn bootstrap() -> Result<AppState, sqlx::Error> {
+    if std::env::var("JWT_SECRET").is_err() {
+        return Err(sqlx::Error::Configuration("JWT_SECRET is not set".into()));
+    }
+    let pool = connect().await?;
+    migrate(&pool).await?;
+    Ok(AppState { db_pool: pool })
+}
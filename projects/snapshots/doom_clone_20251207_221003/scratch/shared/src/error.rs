@@ -0,0 +1,77 @@
+// scratch/shared/src/error.rs
+// Crate-wide error type. Handlers return `Result<_, Error>` and rely on the
+// `ResponseError` impl to render the right status code, so the conflict /
+// not-found / validation mapping lives in exactly one place.
+
+use actix_web::{HttpResponse, ResponseError};
+use actix_web::http::StatusCode;
+use thiserror::Error;
+
+use crate::validation::FieldError;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("database error")]
+    Sqlx(sqlx::Error),
+
+    #[error("email already exists")]
+    EmailExists,
+
+    #[error("not found")]
+    NotFound,
+
+    #[error("validation failed")]
+    Validation(Vec<FieldError>),
+
+    #[error("unauthorized")]
+    Unauthorized,
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(e: sqlx::Error) -> Self {
+        match &e {
+            // A missing row from `fetch_one` is a 404, not a 500.
+            sqlx::Error::RowNotFound => Error::NotFound,
+            // A unique-violation on the users' email constraint is a 409.
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                let constraint = db_err.constraint().unwrap_or("");
+                let table = db_err.table().unwrap_or("");
+                if constraint.contains("email") || table == "users" {
+                    Error::EmailExists
+                } else {
+                    Error::Sqlx(e)
+                }
+            }
+            _ => Error::Sqlx(e),
+        }
+    }
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::Sqlx(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::EmailExists => StatusCode::CONFLICT,
+            Error::NotFound => StatusCode::NOT_FOUND,
+            Error::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Error::Unauthorized => StatusCode::UNAUTHORIZED,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            Error::Validation(fields) => HttpResponse::build(self.status_code()).json(serde_json::json!({
+                "error": "validation_failed",
+                "details": fields,
+            })),
+            Error::EmailExists => HttpResponse::build(self.status_code())
+                .json(serde_json::json!({"error": "email_exists"})),
+            Error::NotFound => HttpResponse::build(self.status_code())
+                .json(serde_json::json!({"error": "not_found"})),
+            Error::Unauthorized => HttpResponse::build(self.status_code())
+                .json(serde_json::json!({"error": "unauthorized"})),
+            Error::Sqlx(_) => HttpResponse::build(self.status_code())
+                .json(serde_json::json!({"error": "internal_error"})),
+        }
+    }
+}
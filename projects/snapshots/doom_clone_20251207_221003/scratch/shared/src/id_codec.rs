@@ -0,0 +1,62 @@
+// scratch/shared/src/id_codec.rs
+// Reversible encoder for turning internal sequential ids into short, opaque,
+// URL-safe public strings (and back). Built on sqids with a crate-wide alphabet
+// and minimum length so encoded ids don't leak row counts or ordering.
+
+use once_cell::sync::Lazy;
+use sqids::Sqids;
+
+// Shuffled alphabet + a minimum length keep the output opaque and uniform.
+const ALPHABET: &str = "fedcbagihjklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const MIN_LENGTH: u8 = 8;
+
+static CODEC: Lazy<Sqids> = Lazy::new(|| {
+    Sqids::builder()
+        .alphabet(ALPHABET.chars().collect())
+        .min_length(MIN_LENGTH)
+        .build()
+        .expect("valid sqids configuration")
+});
+
+/// Encode an internal numeric id into its public string form.
+pub fn encode(id: u64) -> String {
+    CODEC.encode(&[id]).expect("sqids encode")
+}
+
+/// Decode a public string back into the internal id, or `None` if malformed.
+pub fn decode(s: &str) -> Option<u64> {
+    let nums = CODEC.decode(s);
+    // A well-formed code round-trips to exactly one number; reject anything else
+    // (including codes that don't canonically re-encode to the same string).
+    match nums.as_slice() {
+        [id] if encode(*id) == s => Some(*id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn roundtrip() {
+        for id in [0u64, 1, 42, 1000, u32::MAX as u64] {
+            assert_eq!(decode(&encode(id)), Some(id));
+        }
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        assert_eq!(decode(""), None);
+        assert_eq!(decode("!!!not-valid!!!"), None);
+    }
+
+    #[test]
+    fn no_collisions_within_batch() {
+        let mut seen = HashSet::new();
+        for id in 0u64..1000 {
+            assert!(seen.insert(encode(id)), "collision at id {id}");
+        }
+    }
+}
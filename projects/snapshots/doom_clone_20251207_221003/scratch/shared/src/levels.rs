@@ -0,0 +1,182 @@
+// scratch/shared/src/levels.rs
+// Level persistence + CRUD API using Actix-web + SQLx (PostgreSQL)
+// Endpoints (all wrapped by AuthGuard):
+//  - GET    /levels
+//  - POST   /levels
+//  - GET    /levels/{id}
+//  - PUT    /levels/{id}
+//  - DELETE /levels/{id}
+//
+// Mirrors the users.rs module: a FromRow row type, a repository of async
+// functions over a &PgPool, and an Actix config(cfg) entry point.
+
+use actix_web::{web, HttpResponse};
+use sqlx::PgPool;
+use sqlx::types::Json;
+use serde::{Serialize, Deserialize};
+use validator::Validate;
+
+use crate::users::{AppState, AuthGuard};
+use crate::rbac::RequirePermission;
+use crate::error::Error;
+use crate::validation;
+use crate::id_codec;
+
+// The levels table is created by migration `migrations/0002_levels.sql`.
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LevelNode {
+    pub id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub t: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Level {
+    pub id: i32,
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    // Stored as a JSONB column; `Json<T>` handles the (de)serialization.
+    pub nodes: Json<Vec<LevelNode>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LevelResponse {
+    // Opaque public id; the raw SERIAL never leaves the service.
+    pub id: String,
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    pub nodes: Vec<LevelNode>,
+}
+
+impl From<Level> for LevelResponse {
+    fn from(l: Level) -> Self {
+        LevelResponse {
+            id: id_codec::encode(l.id as u64),
+            name: l.name,
+            width: l.width,
+            height: l.height,
+            nodes: l.nodes.0,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewLevel {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    #[validate(range(min = 1, max = 4096))]
+    pub width: i32,
+    #[validate(range(min = 1, max = 4096))]
+    pub height: i32,
+    #[serde(default)]
+    pub nodes: Vec<LevelNode>,
+}
+
+// --------------------
+// Repository
+// --------------------
+pub async fn create(pool: &PgPool, new: &NewLevel) -> Result<Level, sqlx::Error> {
+    sqlx::query_as::<_, Level>(
+        "INSERT INTO levels (name, width, height, nodes) VALUES ($1, $2, $3, $4) \
+         RETURNING id, name, width, height, nodes",
+    )
+    .bind(&new.name)
+    .bind(new.width)
+    .bind(new.height)
+    .bind(Json(&new.nodes))
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn list(pool: &PgPool) -> Result<Vec<Level>, sqlx::Error> {
+    sqlx::query_as::<_, Level>("SELECT id, name, width, height, nodes FROM levels ORDER BY id")
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get(pool: &PgPool, id: i32) -> Result<Level, sqlx::Error> {
+    sqlx::query_as::<_, Level>(
+        "SELECT id, name, width, height, nodes FROM levels WHERE id = $1",
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn update(pool: &PgPool, id: i32, new: &NewLevel) -> Result<Level, sqlx::Error> {
+    sqlx::query_as::<_, Level>(
+        "UPDATE levels SET name = $2, width = $3, height = $4, nodes = $5 WHERE id = $1 \
+         RETURNING id, name, width, height, nodes",
+    )
+    .bind(id)
+    .bind(&new.name)
+    .bind(new.width)
+    .bind(new.height)
+    .bind(Json(&new.nodes))
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete(pool: &PgPool, id: i32) -> Result<u64, sqlx::Error> {
+    let res = sqlx::query("DELETE FROM levels WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(res.rows_affected())
+}
+
+// --------------------
+// Route configuration
+// --------------------
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/levels")
+            // AuthGuard is outermost so it injects Claims before RequirePermission reads them.
+            .wrap(RequirePermission("level"))
+            .wrap(AuthGuard)
+            .route("", web::get(list_levels).post(create_level))
+            .route("/{id}", web::get(get_level).put(update_level).delete(delete_level)),
+    );
+}
+
+// --------------------
+// Handlers
+// --------------------
+async fn list_levels(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let rows = list(&state.db_pool).await?;
+    let resp: Vec<LevelResponse> = rows.into_iter().map(LevelResponse::from).collect();
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+async fn get_level(state: web::Data<AppState>, path: web::Path<String>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let row = get(&state.db_pool, id).await?;
+    Ok(HttpResponse::Ok().json(LevelResponse::from(row)))
+}
+
+async fn create_level(state: web::Data<AppState>, payload: web::Json<NewLevel>) -> Result<HttpResponse, Error> {
+    payload.validate().map_err(|e| Error::Validation(validation::field_errors(&e)))?;
+    let row = create(&state.db_pool, &payload.into_inner()).await?;
+    Ok(HttpResponse::Created().json(LevelResponse::from(row)))
+}
+
+async fn update_level(state: web::Data<AppState>, path: web::Path<String>, payload: web::Json<NewLevel>) -> Result<HttpResponse, Error> {
+    payload.validate().map_err(|e| Error::Validation(validation::field_errors(&e)))?;
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let row = update(&state.db_pool, id, &payload.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(LevelResponse::from(row)))
+}
+
+async fn delete_level(state: web::Data<AppState>, path: web::Path<String>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let affected = delete(&state.db_pool, id).await?;
+    if affected == 0 {
+        Err(Error::NotFound)
+    } else {
+        Ok(HttpResponse::NoContent().finish())
+    }
+}
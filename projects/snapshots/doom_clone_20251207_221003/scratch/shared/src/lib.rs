@@ -0,0 +1,13 @@
+// scratch/shared/src/lib.rs
+// HTTP service crate: user auth + game-content editor backend.
+
+pub mod db;
+pub mod id_codec;
+pub mod validation;
+pub mod error;
+pub mod users;
+pub mod rbac;
+pub mod levels;
+pub mod weapons;
+pub mod monsters;
+pub mod assets;
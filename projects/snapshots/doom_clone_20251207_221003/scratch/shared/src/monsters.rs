@@ -0,0 +1,159 @@
+// scratch/shared/src/monsters.rs
+// Monster persistence + CRUD API using Actix-web + SQLx (PostgreSQL)
+// Endpoints (all wrapped by AuthGuard):
+//  - GET    /monsters
+//  - POST   /monsters
+//  - GET    /monsters/{id}
+//  - PUT    /monsters/{id}
+//  - DELETE /monsters/{id}
+//
+// Mirrors the users.rs module: a FromRow row type, a repository of async
+// functions over a &PgPool, and an Actix config(cfg) entry point.
+
+use actix_web::{web, HttpResponse};
+use sqlx::PgPool;
+use serde::{Serialize, Deserialize};
+
+use crate::users::{AppState, AuthGuard};
+use crate::error::Error;
+use crate::id_codec;
+
+// The monsters table is created by migration `migrations/0004_monsters.sql`.
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Monster {
+    pub id: i32,
+    pub kind: String,
+    pub health: i32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MonsterResponse {
+    // Opaque public id; the raw SERIAL never leaves the service.
+    pub id: String,
+    pub kind: String,
+    pub health: i32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+}
+
+impl From<Monster> for MonsterResponse {
+    fn from(m: Monster) -> Self {
+        MonsterResponse {
+            id: id_codec::encode(m.id as u64),
+            kind: m.kind,
+            health: m.health,
+            pos_x: m.pos_x,
+            pos_y: m.pos_y,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewMonster {
+    pub kind: String,
+    pub health: i32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+}
+
+// --------------------
+// Repository
+// --------------------
+pub async fn create(pool: &PgPool, new: &NewMonster) -> Result<Monster, sqlx::Error> {
+    sqlx::query_as::<_, Monster>(
+        "INSERT INTO monsters (kind, health, pos_x, pos_y) VALUES ($1, $2, $3, $4) \
+         RETURNING id, kind, health, pos_x, pos_y",
+    )
+    .bind(&new.kind)
+    .bind(new.health)
+    .bind(new.pos_x)
+    .bind(new.pos_y)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn list(pool: &PgPool) -> Result<Vec<Monster>, sqlx::Error> {
+    sqlx::query_as::<_, Monster>("SELECT id, kind, health, pos_x, pos_y FROM monsters ORDER BY id")
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get(pool: &PgPool, id: i32) -> Result<Monster, sqlx::Error> {
+    sqlx::query_as::<_, Monster>("SELECT id, kind, health, pos_x, pos_y FROM monsters WHERE id = $1")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn update(pool: &PgPool, id: i32, new: &NewMonster) -> Result<Monster, sqlx::Error> {
+    sqlx::query_as::<_, Monster>(
+        "UPDATE monsters SET kind = $2, health = $3, pos_x = $4, pos_y = $5 WHERE id = $1 \
+         RETURNING id, kind, health, pos_x, pos_y",
+    )
+    .bind(id)
+    .bind(&new.kind)
+    .bind(new.health)
+    .bind(new.pos_x)
+    .bind(new.pos_y)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete(pool: &PgPool, id: i32) -> Result<u64, sqlx::Error> {
+    let res = sqlx::query("DELETE FROM monsters WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(res.rows_affected())
+}
+
+// --------------------
+// Route configuration
+// --------------------
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/monsters")
+            .wrap(AuthGuard)
+            .route("", web::get(list_monsters).post(create_monster))
+            .route("/{id}", web::get(get_monster).put(update_monster).delete(delete_monster)),
+    );
+}
+
+// --------------------
+// Handlers
+// --------------------
+async fn list_monsters(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let rows = list(&state.db_pool).await?;
+    let resp: Vec<MonsterResponse> = rows.into_iter().map(MonsterResponse::from).collect();
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+async fn get_monster(state: web::Data<AppState>, path: web::Path<String>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let row = get(&state.db_pool, id).await?;
+    Ok(HttpResponse::Ok().json(MonsterResponse::from(row)))
+}
+
+async fn create_monster(state: web::Data<AppState>, payload: web::Json<NewMonster>) -> Result<HttpResponse, Error> {
+    let row = create(&state.db_pool, &payload.into_inner()).await?;
+    Ok(HttpResponse::Created().json(MonsterResponse::from(row)))
+}
+
+async fn update_monster(state: web::Data<AppState>, path: web::Path<String>, payload: web::Json<NewMonster>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let row = update(&state.db_pool, id, &payload.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(MonsterResponse::from(row)))
+}
+
+async fn delete_monster(state: web::Data<AppState>, path: web::Path<String>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let affected = delete(&state.db_pool, id).await?;
+    if affected == 0 {
+        Err(Error::NotFound)
+    } else {
+        Ok(HttpResponse::NoContent().finish())
+    }
+}
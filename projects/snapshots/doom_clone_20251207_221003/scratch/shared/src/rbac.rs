@@ -0,0 +1,118 @@
+// scratch/shared/src/rbac.rs
+// Role-based access control. A parameterized `RequirePermission(resource)` guard,
+// modeled on the `AuthGuard` Transform in users.rs, reads the `Claims` injected
+// by the JWT middleware and checks whether the caller's role has been granted
+// the action the request is attempting. Safe (read-only) methods are left open
+// so that a `viewer` can still `GET` content.
+//
+// The action is `resource:verb`, where `verb` is `delete` for DELETE requests
+// and `write` for every other mutating method. Grants live in the
+// `role_permissions` join table seeded by `migrations/0005_rbac.sql`; a role may
+// perform an action only when a matching row exists, so new actions are denied
+// by default until explicitly granted.
+
+use actix_web::{web, HttpResponse, Error as ActixError, HttpMessage};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::Method;
+use actix_service::{Service, Transform};
+use futures_util::future::{LocalBoxFuture, Ready, ready};
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use uuid::Uuid;
+
+use crate::users::{AppState, Claims};
+
+// The `role` column, the `user_role` enum, and the `roles`/`permissions`/
+// `role_permissions` tables are created by `migrations/0005_rbac.sql`.
+
+/// Guards a scope's mutating routes behind the permissions for `resource`.
+///
+/// The stored string is the resource half of the action (e.g. `"level"`); the
+/// verb half is derived per request from the HTTP method.
+#[derive(Clone, Copy)]
+pub struct RequirePermission(pub &'static str);
+
+impl<S, B> Transform<S> for RequirePermission
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = ActixError> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = ActixError;
+    type Transform = RequirePermissionMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequirePermissionMiddleware { service: Rc::new(service), resource: self.0 }))
+    }
+}
+
+pub struct RequirePermissionMiddleware<S> {
+    service: Rc<S>,
+    resource: &'static str,
+}
+
+impl<S, B> Service<ServiceRequest> for RequirePermissionMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = ActixError> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = ActixError;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        // Safe methods are readable by anyone already past the AuthGuard.
+        if matches!(*req.method(), Method::GET | Method::HEAD | Method::OPTIONS) {
+            return Box::pin(async move { service.call(req).await });
+        }
+
+        let verb = if *req.method() == Method::DELETE { "delete" } else { "write" };
+        let action = format!("{}:{}", self.resource, verb);
+
+        let claims = req.extensions().get::<Claims>().cloned();
+        let pool = req.app_data::<web::Data<AppState>>().map(|d| d.db_pool.clone());
+
+        Box::pin(async move {
+            let forbidden = |req: ServiceRequest| {
+                let resp = HttpResponse::Forbidden().json(serde_json::json!({"error": "forbidden"}));
+                Ok(req.into_response(resp.into_body()))
+            };
+
+            let user_id = claims
+                .as_ref()
+                .and_then(|c| Uuid::parse_str(&c.sub).ok());
+            let (user_id, pool) = match (user_id, pool) {
+                (Some(id), Some(pool)) => (id, pool),
+                _ => return forbidden(req),
+            };
+
+            // True only when the caller's role has been granted this action.
+            let granted: Option<bool> = sqlx::query_scalar(
+                "SELECT EXISTS (\
+                     SELECT 1 FROM users u \
+                     JOIN role_permissions rp ON rp.role = u.role \
+                     JOIN permissions p ON p.id = rp.permission_id \
+                     WHERE u.id = $1 AND p.action = $2)",
+            )
+            .bind(user_id)
+            .bind(&action)
+            .fetch_optional(&pool)
+            .await
+            .ok()
+            .flatten();
+
+            match granted {
+                Some(true) => service.call(req).await,
+                _ => forbidden(req),
+            }
+        })
+    }
+}
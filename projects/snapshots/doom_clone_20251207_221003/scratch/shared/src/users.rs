@@ -17,41 +17,46 @@ use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
-use regex::Regex;
+use validator::Validate;
+use crate::validation;
+use crate::error::Error;
+use argon2::{Argon2, PasswordHasher, PasswordVerifier, PasswordHash};
+use argon2::password_hash::{SaltString, rand_core::OsRng};
+use jsonwebtoken::{encode, decode, Header, Validation, EncodingKey, DecodingKey, Algorithm};
 use once_cell::sync::Lazy;
 
-// --------------------
-// MIGRATIONS OUTLINE
-// --------------------
-// The following SQL outlines the initial migration to create the users table.
-// This should be placed in a migrations/ directory with proper tooling (sqlx migrate).
-//
-// Migration: create_users_table
-// -- Up
-// CREATE EXTENSION IF NOT EXISTS "uuid-ossp"; -- enable UUID generation (or use gen_random_uuid())
-// CREATE TABLE IF NOT EXISTS users (
-//   id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
-//   name VARCHAR(255) NOT NULL,
-//   email VARCHAR(255) NOT NULL UNIQUE,
-//   created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
-// );
-//
-// -- Down
-// DROP TABLE IF EXISTS users;
-// --------------------
+// A throwaway hash verified on the "no such user" path so login spends the same
+// Argon2 work whether or not the email exists, closing a user-enumeration
+// timing oracle. The verify result is discarded, so this path always rejects.
+static DUMMY_PASSWORD_HASH: Lazy<String> = Lazy::new(|| {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(b"dummy-password", &salt)
+        .expect("hashing a fixed password cannot fail")
+        .to_string()
+});
+
+// The users table is created by migration `migrations/0001_users.sql`, applied
+// at startup by the `db` module's migration runner.
 
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
 pub struct User {
     pub id: Uuid,
     pub name: String,
     pub email: String,
+    #[serde(skip_serializing)]
+    pub password_hash: String,
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct NewUser {
+    #[validate(length(min = 1, max = 255))]
     pub name: String,
+    #[validate(email)]
     pub email: String,
+    #[validate(length(min = 8, max = 255))]
+    pub password: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -73,28 +78,116 @@ impl From<User> for UserResponse {
     }
 }
 
-// Validation regex for basic email format checking (very lightweight)
-static EMAIL_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$").unwrap()
-});
+// --------------------
+// JWT claims + token issuing
+// --------------------
+// Tokens are signed with HS256 using the secret from the `JWT_SECRET` env var.
+// The claims carry the user id (`sub`), issued-at (`iat`) and expiry (`exp`).
 
-static NAME_MIN_LEN: usize = 1;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub iat: i64,
+    pub exp: i64,
+}
 
-fn validate_new_user(input: &NewUser) -> Result<(), Vec<String>> {
-    let mut errors: Vec<String> = Vec::new();
-    if input.name.trim().len() < NAME_MIN_LEN {
-        errors.push("name_required".to_string());
-    }
-    if !EMAIL_REGEX.is_match(&input.email) {
-        errors.push("email_invalid".to_string());
+// Default token lifetime (seconds); overridable via `JWT_TTL_SECONDS`.
+static JWT_DEFAULT_TTL_SECS: i64 = 3600;
+
+// Fail closed: there is no default secret. `bootstrap` validates this at
+// startup, so a missing var here means the process was started incorrectly
+// rather than exposing a publicly-known signing key.
+fn jwt_secret() -> String {
+    std::env::var("JWT_SECRET").expect("JWT_SECRET must be set")
+}
+
+fn jwt_ttl() -> i64 {
+    std::env::var("JWT_TTL_SECONDS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(JWT_DEFAULT_TTL_SECS)
+}
+
+/// Issue a signed HS256 token for `user_id`, valid for `ttl_secs` from `now`.
+fn issue_token(user_id: Uuid, now: DateTime<Utc>, ttl_secs: i64, secret: &str) -> Result<String, jsonwebtoken::errors::Error> {
+    let claims = Claims {
+        sub: user_id.to_string(),
+        iat: now.timestamp(),
+        exp: (now + chrono::Duration::seconds(ttl_secs)).timestamp(),
+    };
+    encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(secret.as_bytes()))
+}
+
+/// Decode and validate a token, checking signature and expiry.
+fn decode_token(token: &str, secret: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+    let data = decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::new(Algorithm::HS256),
+    )?;
+    Ok(data.claims)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginRequest {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    pub token: String,
+    pub token_type: &'static str,
+}
+
+async fn login(state: web::Data<AppState>, payload: web::Json<LoginRequest>) -> Result<HttpResponse, ActixError> {
+    let pool = &state.db_pool;
+    let creds = payload.into_inner();
+
+    let user = sqlx::query_as::<_, User>(
+        "SELECT id, name, email, password_hash, created_at FROM users WHERE email = $1",
+    )
+    .bind(&creds.email)
+    .fetch_optional(pool)
+    .await
+    .map_err(ActixError::from)?;
+
+    // Collapse "no such user" and "bad password" into one response to avoid
+    // leaking which emails are registered.
+    let unauthorized = || {
+        HttpResponse::Unauthorized().json(serde_json::json!({"error": "invalid_credentials"}))
+    };
+
+    let user = match user {
+        Some(u) => u,
+        None => {
+            // Do the same Argon2 work as the happy path so the response time
+            // does not reveal whether the email is registered.
+            if let Ok(dummy) = PasswordHash::new(&DUMMY_PASSWORD_HASH) {
+                let _ = Argon2::default().verify_password(creds.password.as_bytes(), &dummy);
+            }
+            return Ok(unauthorized());
+        }
+    };
+
+    let parsed = match PasswordHash::new(&user.password_hash) {
+        Ok(h) => h,
+        Err(_) => return Ok(unauthorized()),
+    };
+    if Argon2::default().verify_password(creds.password.as_bytes(), &parsed).is_err() {
+        return Ok(unauthorized());
     }
-    if errors.is_empty() { Ok(()) } else { Err(errors) }
+
+    let token = issue_token(user.id, Utc::now(), jwt_ttl(), &jwt_secret())
+        .map_err(|_| ActixError::from(HttpResponse::InternalServerError().finish()))?;
+    Ok(HttpResponse::Ok().json(LoginResponse { token, token_type: "Bearer" }))
 }
 
 // --------------------
-// Authentication placeholder middleware
+// Authentication middleware
 // --------------------
 use actix_service::{Service, Transform};
+use actix_web::HttpMessage;
 use actix_web::dev::{ServiceRequest, ServiceResponse, Payload};
 use actix_web::http::StatusCode;
 use futures_util::future::{LocalBoxFuture, Ready, ready};
@@ -136,42 +229,30 @@ where
     }
 
     fn call(&mut self, req: ServiceRequest) -> Self::Future {
-        // Simple placeholder: require Authorization header with Bearer token
-        let token_ok = {
-            if let Some(header_value) = req.headers().get(actix_web::http::header::AUTHORIZATION) {
-                if let Ok(s) = header_value.to_str() {
-                    if s.starts_with("Bearer ") {
-                        let token = &s[7..];
-                        // Accept a hard-coded token or an env var override
-                        let env_token = std::env::var("AUTH_TOKEN").ok();
-                        if token == "test-token" || env_token.as_deref() == Some(token) {
-                            true
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                }
-            } else {
-                false
+        // Require a `Bearer <jwt>` Authorization header, decode it with the
+        // shared secret, and stash the validated claims in the request
+        // extensions so downstream handlers can read the authenticated id.
+        let claims = req
+            .headers()
+            .get(actix_web::http::header::AUTHORIZATION)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|s| s.strip_prefix("Bearer "))
+            .and_then(|token| decode_token(token, &jwt_secret()).ok());
+
+        match claims {
+            Some(claims) => {
+                req.extensions_mut().insert(claims);
+                let fut = self.service.call(req);
+                Box::pin(async move {
+                    let res = fut.await?;
+                    Ok(res)
+                })
             }
-        };
-
-        if token_ok {
-            let fut = self.service.call(req);
-            Box::pin(async move {
-                let res = fut.await?;
-                Ok(res)
-            })
-        } else {
-            Box::pin(async {
+            None => Box::pin(async {
                 let resp = HttpResponse::Unauthorized()
                     .json(serde_json::json!({"error": "unauthorized"}));
                 Ok(req.into_response(resp.into_body()))
-            })
+            }),
         }
     }
 }
@@ -180,11 +261,16 @@ where
 // Route configuration
 // --------------------
 pub fn config(cfg: &mut web::ServiceConfig) {
+    // Public auth routes (no guard — this is how a client signs up and obtains
+    // a token). Signup must be reachable without a token, otherwise no user can
+    // ever be created and login could never succeed.
+    cfg.route("/auth/signup", web::post().to(create_user));
+    cfg.route("/auth/login", web::post().to(login));
     // Routes under /users
     cfg.service(
         web::scope("/users")
             .wrap(AuthGuard)
-            .route("/", web::get(list_users).post(create_user))
+            .route("", web::get(list_users))
             .route("/{id}", web::get(get_user)),
     );
 }
@@ -192,14 +278,13 @@ pub fn config(cfg: &mut web::ServiceConfig) {
 // --------------------
 // Handlers
 // --------------------
-async fn list_users(state: web::Data<AppState>) -> Result<HttpResponse, ActixError> {
+async fn list_users(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
     let pool = &state.db_pool;
     let rows = sqlx::query_as::<_, User>(
-        "SELECT id, name, email, created_at FROM users ORDER BY created_at DESC",
+        "SELECT id, name, email, password_hash, created_at FROM users ORDER BY created_at DESC",
     )
     .fetch_all(pool)
-    .await
-    .map_err(|e| ActixError::from(e))?;
+    .await?;
 
     let resp: Vec<UserResponse> = rows.into_iter().map(UserResponse::from).collect();
     Ok(HttpResponse::Ok().json(resp))
@@ -210,50 +295,43 @@ pub struct AppState {
     pub db_pool: PgPool,
 }
 
-async fn get_user(state: web::Data<AppState>, path: web::Path<Uuid>) -> Result<HttpResponse, ActixError> {
+async fn get_user(state: web::Data<AppState>, path: web::Path<Uuid>) -> Result<HttpResponse, Error> {
     let pool = &state.db_pool;
     let id = path.into_inner();
     let row = sqlx::query_as::<_, User>(
-        "SELECT id, name, email, created_at FROM users WHERE id = $1",
+        "SELECT id, name, email, password_hash, created_at FROM users WHERE id = $1",
     )
     .bind(id)
     .fetch_one(pool)
-    .await
-    .map_err(|e| {
-        if let sqlx::Error::RowNotFound = e {
-            ActixError::from(HttpResponse::NotFound().json(serde_json::json!({"error": "not_found"})))
-        } else {
-            ActixError::from(e)
-        }
-    })?;
+    .await?;
 
     let resp = UserResponse::from(row);
     Ok(HttpResponse::Ok().json(resp))
 }
 
-async fn create_user(state: web::Data<AppState>, payload: web::Json<NewUser>) -> Result<HttpResponse, ActixError> {
+async fn create_user(state: web::Data<AppState>, payload: web::Json<NewUser>) -> Result<HttpResponse, Error> {
     // Validate input
-    match validate_new_user(&payload) {
-        Ok(()) => {},
-        Err(errors) => {
-            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
-                "error": "validation_failed",
-                "details": errors,
-            })));
-        }
-    }
+    payload.validate().map_err(|e| Error::Validation(validation::field_errors(&e)))?;
 
     let pool = &state.db_pool;
     let new = payload.into_inner();
-    // Insert and return created user
+
+    // Hash the submitted password with Argon2id before it ever touches the DB.
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(new.password.as_bytes(), &salt)
+        .map_err(|_| Error::Sqlx(sqlx::Error::Protocol("password hashing failed".into())))?
+        .to_string();
+
+    // Insert and return created user; a duplicate email maps to 409 via `From`.
     let row = sqlx::query_as::<_, User>(
-        "INSERT INTO users (name, email) VALUES ($1, $2) RETURNING id, name, email, created_at",
+        "INSERT INTO users (name, email, password_hash) VALUES ($1, $2, $3) RETURNING id, name, email, password_hash, created_at",
     )
     .bind(new.name)
     .bind(new.email)
+    .bind(password_hash)
     .fetch_one(pool)
-    .await
-    .map_err(|e| ActixError::from(e))?;
+    .await?;
 
     let resp = UserResponse::from(row);
     Ok(HttpResponse::Created().json(resp))
@@ -268,17 +346,42 @@ mod tests {
 
     #[test]
     fn test_validate_new_user_valid() {
-        let input = NewUser { name: "Alice".to_string(), email: "alice@example.com".to_string() };
-        assert!(validate_new_user(&input).is_ok());
+        let input = NewUser { name: "Alice".to_string(), email: "alice@example.com".to_string(), password: "hunter2!".to_string() };
+        assert!(input.validate().is_ok());
     }
 
     #[test]
     fn test_validate_new_user_invalid() {
-        let input = NewUser { name: "".to_string(), email: "not-an-email".to_string() };
-        let res = validate_new_user(&input);
-        assert!(res.is_err());
-        let errs = res.unwrap_err();
-        assert!(errs.iter().any(|e| e == "name_required"));
-        assert!(errs.iter().any(|e| e == "email_invalid"));
+        let input = NewUser { name: "".to_string(), email: "not-an-email".to_string(), password: "short".to_string() };
+        let errs = input.validate().unwrap_err();
+        let fields = validation::field_errors(&errs);
+        assert!(fields.iter().any(|f| f.field == "name"));
+        assert!(fields.iter().any(|f| f.field == "email"));
+        assert!(fields.iter().any(|f| f.field == "password"));
+    }
+
+    #[test]
+    fn test_token_roundtrip() {
+        let secret = "unit-test-secret";
+        let id = Uuid::new_v4();
+        let token = issue_token(id, Utc::now(), 3600, secret).unwrap();
+        let claims = decode_token(&token, secret).unwrap();
+        assert_eq!(claims.sub, id.to_string());
+        assert!(claims.exp > claims.iat);
+    }
+
+    #[test]
+    fn test_token_expired_is_rejected() {
+        let secret = "unit-test-secret";
+        // Issue a token that expired an hour ago.
+        let issued_at = Utc::now() - chrono::Duration::seconds(7200);
+        let token = issue_token(Uuid::new_v4(), issued_at, 3600, secret).unwrap();
+        assert!(decode_token(&token, secret).is_err());
+    }
+
+    #[test]
+    fn test_token_bad_signature_is_rejected() {
+        let token = issue_token(Uuid::new_v4(), Utc::now(), 3600, "secret-a").unwrap();
+        assert!(decode_token(&token, "secret-b").is_err());
     }
 }
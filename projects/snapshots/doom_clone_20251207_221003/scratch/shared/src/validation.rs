@@ -0,0 +1,28 @@
+// scratch/shared/src/validation.rs
+// Shared helpers for turning `validator::ValidationErrors` into a consistent
+// 422 Unprocessable Entity response. Keeping this in one place means every
+// endpoint that derives `Validate` reports failures the same way: an array of
+// { field, code } objects rather than flat opaque strings.
+
+use serde::Serialize;
+use validator::ValidationErrors;
+
+#[derive(Debug, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+}
+
+/// Flatten a `ValidationErrors` tree into a list of (field, failure code) pairs.
+pub fn field_errors(errors: &ValidationErrors) -> Vec<FieldError> {
+    errors
+        .field_errors()
+        .iter()
+        .flat_map(|(field, errs)| {
+            errs.iter().map(move |e| FieldError {
+                field: field.to_string(),
+                code: e.code.to_string(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,163 @@
+// scratch/shared/src/weapons.rs
+// Weapon persistence + CRUD API using Actix-web + SQLx (PostgreSQL)
+// Endpoints (all wrapped by AuthGuard):
+//  - GET    /weapons
+//  - POST   /weapons
+//  - GET    /weapons/{id}
+//  - PUT    /weapons/{id}
+//  - DELETE /weapons/{id}
+//
+// Mirrors the users.rs module: a FromRow row type, a repository of async
+// functions over a &PgPool, and an Actix config(cfg) entry point.
+
+use actix_web::{web, HttpResponse};
+use sqlx::PgPool;
+use serde::{Serialize, Deserialize};
+use validator::Validate;
+
+use crate::users::{AppState, AuthGuard};
+use crate::rbac::RequirePermission;
+use crate::error::Error;
+use crate::validation;
+use crate::id_codec;
+
+// The weapons table is created by migration `migrations/0003_weapons.sql`.
+
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Weapon {
+    pub id: i32,
+    pub name: String,
+    pub damage: f32,
+    pub range: f32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WeaponResponse {
+    // Opaque public id; the raw SERIAL never leaves the service.
+    pub id: String,
+    pub name: String,
+    pub damage: f32,
+    pub range: f32,
+}
+
+impl From<Weapon> for WeaponResponse {
+    fn from(w: Weapon) -> Self {
+        WeaponResponse {
+            id: id_codec::encode(w.id as u64),
+            name: w.name,
+            damage: w.damage,
+            range: w.range,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct NewWeapon {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    #[validate(range(min = 0.0, max = 10000.0))]
+    pub damage: f32,
+    #[validate(range(min = 0.0, max = 10000.0))]
+    pub range: f32,
+}
+
+// --------------------
+// Repository
+// --------------------
+pub async fn create(pool: &PgPool, new: &NewWeapon) -> Result<Weapon, sqlx::Error> {
+    sqlx::query_as::<_, Weapon>(
+        "INSERT INTO weapons (name, damage, range) VALUES ($1, $2, $3) \
+         RETURNING id, name, damage, range",
+    )
+    .bind(&new.name)
+    .bind(new.damage)
+    .bind(new.range)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn list(pool: &PgPool) -> Result<Vec<Weapon>, sqlx::Error> {
+    sqlx::query_as::<_, Weapon>("SELECT id, name, damage, range FROM weapons ORDER BY id")
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get(pool: &PgPool, id: i32) -> Result<Weapon, sqlx::Error> {
+    sqlx::query_as::<_, Weapon>("SELECT id, name, damage, range FROM weapons WHERE id = $1")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn update(pool: &PgPool, id: i32, new: &NewWeapon) -> Result<Weapon, sqlx::Error> {
+    sqlx::query_as::<_, Weapon>(
+        "UPDATE weapons SET name = $2, damage = $3, range = $4 WHERE id = $1 \
+         RETURNING id, name, damage, range",
+    )
+    .bind(id)
+    .bind(&new.name)
+    .bind(new.damage)
+    .bind(new.range)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete(pool: &PgPool, id: i32) -> Result<u64, sqlx::Error> {
+    let res = sqlx::query("DELETE FROM weapons WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(res.rows_affected())
+}
+
+// --------------------
+// Route configuration
+// --------------------
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/weapons")
+            // AuthGuard is outermost so it injects Claims before RequirePermission reads them.
+            .wrap(RequirePermission("weapon"))
+            .wrap(AuthGuard)
+            .route("", web::get(list_weapons).post(create_weapon))
+            .route("/{id}", web::get(get_weapon).put(update_weapon).delete(delete_weapon)),
+    );
+}
+
+// --------------------
+// Handlers
+// --------------------
+async fn list_weapons(state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    let rows = list(&state.db_pool).await?;
+    let resp: Vec<WeaponResponse> = rows.into_iter().map(WeaponResponse::from).collect();
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+async fn get_weapon(state: web::Data<AppState>, path: web::Path<String>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let row = get(&state.db_pool, id).await?;
+    Ok(HttpResponse::Ok().json(WeaponResponse::from(row)))
+}
+
+async fn create_weapon(state: web::Data<AppState>, payload: web::Json<NewWeapon>) -> Result<HttpResponse, Error> {
+    payload.validate().map_err(|e| Error::Validation(validation::field_errors(&e)))?;
+    let row = create(&state.db_pool, &payload.into_inner()).await?;
+    Ok(HttpResponse::Created().json(WeaponResponse::from(row)))
+}
+
+async fn update_weapon(state: web::Data<AppState>, path: web::Path<String>, payload: web::Json<NewWeapon>) -> Result<HttpResponse, Error> {
+    payload.validate().map_err(|e| Error::Validation(validation::field_errors(&e)))?;
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let row = update(&state.db_pool, id, &payload.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(WeaponResponse::from(row)))
+}
+
+async fn delete_weapon(state: web::Data<AppState>, path: web::Path<String>) -> Result<HttpResponse, Error> {
+    let id = id_codec::decode(&path.into_inner()).ok_or(Error::NotFound)? as i32;
+    let affected = delete(&state.db_pool, id).await?;
+    if affected == 0 {
+        Err(Error::NotFound)
+    } else {
+        Ok(HttpResponse::NoContent().finish())
+    }
+}